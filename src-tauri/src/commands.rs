@@ -1,9 +1,13 @@
 use crate::state::AppState;
 use crate::utils;
 use crate::watcher;
-use std::fs;
+use serde::Serialize;
+use std::collections::VecDeque;
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, UNIX_EPOCH};
 use tauri::AppHandle;
+use tokio::io::AsyncWriteExt;
+use uuid::Uuid;
 
 /// Basic ping command to test IPC communication
 #[tauri::command]
@@ -29,88 +33,155 @@ pub fn example_error(should_error: bool) -> Result<String, String> {
 
 /// Read a file and return its contents as a string
 #[tauri::command]
-pub fn read_file(path: String) -> Result<String, String> {
+pub async fn read_file(path: String) -> Result<String, String> {
     let path_buf = PathBuf::from(&path);
-    
-    if !path_buf.exists() {
-        return Err(format!("File does not exist: {}", path));
-    }
-    
-    if !path_buf.is_file() {
+
+    let metadata = tokio::fs::metadata(&path_buf)
+        .await
+        .map_err(|_| format!("File does not exist: {}", path))?;
+
+    if !metadata.is_file() {
         return Err(format!("Path is not a file: {}", path));
     }
-    
-    fs::read_to_string(&path_buf)
+
+    tokio::fs::read_to_string(&path_buf)
+        .await
         .map_err(|e| format!("Failed to read file {}: {}", path, e))
 }
 
+/// Write a file atomically via a temp file in the same directory, then rename over the destination
+#[tauri::command]
+pub async fn write_file(path: String, contents: String) -> Result<(), String> {
+    let path_buf = PathBuf::from(&path);
+
+    if let Some(parent) = path_buf.parent() {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .map_err(|e| format!("Failed to create parent directories for {}: {}", path, e))?;
+    }
+
+    let file_name = path_buf
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| format!("Invalid file name: {}", path))?;
+
+    let tmp_name = format!(".{}.tmp-{}", file_name, random_suffix());
+    let tmp_path = path_buf
+        .parent()
+        .map(|parent| parent.join(&tmp_name))
+        .unwrap_or_else(|| PathBuf::from(&tmp_name));
+
+    if let Err(e) = write_and_flush(&tmp_path, &contents).await {
+        let _ = tokio::fs::remove_file(&tmp_path).await;
+        return Err(format!("Failed to write temp file for {}: {}", path, e));
+    }
+
+    if let Err(e) = tokio::fs::rename(&tmp_path, &path_buf).await {
+        let _ = tokio::fs::remove_file(&tmp_path).await;
+        return Err(format!("Failed to rename temp file into place for {}: {}", path, e));
+    }
+
+    Ok(())
+}
+
+/// Write `contents` to `path` and flush it to disk before returning
+async fn write_and_flush(path: &Path, contents: &str) -> std::io::Result<()> {
+    let mut file = tokio::fs::File::create(path).await?;
+    file.write_all(contents.as_bytes()).await?;
+    file.flush().await?;
+    file.sync_all().await
+}
+
+/// Generate a temp file suffix; a UUID avoids same-tick collisions a raw timestamp would have
+fn random_suffix() -> String {
+    Uuid::new_v4().to_string()
+}
+
 /// Get all kit files from the project's .bluekit/kits directory
 #[tauri::command]
-pub fn get_project_kits(project_path: String) -> Result<Vec<String>, String> {
+pub async fn get_project_kits(project_path: String) -> Result<Vec<String>, String> {
     let kits_dir = PathBuf::from(&project_path).join(".bluekit").join("kits");
-    
-    if !kits_dir.exists() {
+
+    if tokio::fs::metadata(&kits_dir).await.is_err() {
         return Ok(Vec::new());
     }
-    
-    let entries = fs::read_dir(&kits_dir)
+
+    let mut entries = tokio::fs::read_dir(&kits_dir)
+        .await
         .map_err(|e| format!("Failed to read kits directory: {}", e))?;
-    
+
     let mut kits = Vec::new();
-    for entry in entries {
-        let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
+    while let Some(entry) = entries
+        .next_entry()
+        .await
+        .map_err(|e| format!("Failed to read directory entry: {}", e))?
+    {
         let path = entry.path();
-        
+
         if path.is_file() {
             if let Some(file_name) = path.file_name().and_then(|n| n.to_str()) {
                 kits.push(file_name.to_string());
             }
         }
     }
-    
+
     Ok(kits)
 }
 
 /// Get project registry from ~/.bluekit/projectRegistry.json
 #[tauri::command]
-pub fn get_project_registry() -> Result<serde_json::Value, String> {
+pub async fn get_project_registry() -> Result<serde_json::Value, String> {
     let registry_path = watcher::get_registry_path()?;
-    
-    if !registry_path.exists() {
+
+    if tokio::fs::metadata(&registry_path).await.is_err() {
         return Ok(serde_json::json!({}));
     }
-    
-    let content = fs::read_to_string(&registry_path)
+
+    let content = tokio::fs::read_to_string(&registry_path)
+        .await
         .map_err(|e| format!("Failed to read registry file: {}", e))?;
-    
+
     let registry: serde_json::Value = serde_json::from_str(&content)
         .map_err(|e| format!("Failed to parse registry JSON: {}", e))?;
-    
+
     Ok(registry)
 }
 
 /// Watch project kits directory for changes
 #[tauri::command]
-pub fn watch_project_kits(
+pub async fn watch_project_kits(
     app_handle: AppHandle,
     project_path: String,
+    notify_on_change: Option<bool>,
 ) -> Result<(), String> {
     let kits_dir = PathBuf::from(&project_path).join(".bluekit").join("kits");
-    
-    if !kits_dir.exists() {
+
+    if tokio::fs::metadata(&kits_dir).await.is_err() {
         return Err(format!("Kits directory does not exist: {:?}", kits_dir));
     }
-    
+
     watcher::watch_directory(
         app_handle,
         kits_dir,
         "project-kits-changed".to_string(),
+        watcher::DEFAULT_DEBOUNCE,
+        notify_on_change.unwrap_or(false),
     )
 }
 
+/// Toggle whether watchers are allowed to raise native OS notifications
+#[tauri::command]
+pub fn set_notifications_enabled(
+    notifications: tauri::State<'_, crate::state::NotificationSettings>,
+    enabled: bool,
+) -> Result<(), String> {
+    notifications.set_enabled(enabled);
+    Ok(())
+}
+
 /// Copy a kit file from global store to project
 #[tauri::command]
-pub fn copy_kit_to_project(
+pub async fn copy_kit_to_project(
     kit_name: String,
     project_path: String,
 ) -> Result<String, String> {
@@ -119,31 +190,33 @@ pub fn copy_kit_to_project(
         .join(".bluekit")
         .join("kits")
         .join(&kit_name);
-    
+
     let project_kit_path = PathBuf::from(&project_path)
         .join(".bluekit")
         .join("kits")
         .join(&kit_name);
-    
+
     // Create project .bluekit/kits directory if it doesn't exist
     if let Some(parent) = project_kit_path.parent() {
-        fs::create_dir_all(parent)
+        tokio::fs::create_dir_all(parent)
+            .await
             .map_err(|e| format!("Failed to create kits directory: {}", e))?;
     }
-    
-    if !global_kit_path.exists() {
+
+    if tokio::fs::metadata(&global_kit_path).await.is_err() {
         return Err(format!("Kit not found in global store: {}", kit_name));
     }
-    
-    fs::copy(&global_kit_path, &project_kit_path)
+
+    tokio::fs::copy(&global_kit_path, &project_kit_path)
+        .await
         .map_err(|e| format!("Failed to copy kit: {}", e))?;
-    
+
     Ok(format!("Kit {} copied to project", kit_name))
 }
 
 /// Copy a blueprint to project
 #[tauri::command]
-pub fn copy_blueprint_to_project(
+pub async fn copy_blueprint_to_project(
     blueprint_id: String,
     project_path: String,
 ) -> Result<String, String> {
@@ -152,103 +225,196 @@ pub fn copy_blueprint_to_project(
         .join(".bluekit")
         .join("blueprints")
         .join(&blueprint_id);
-    
+
     let project_blueprint_dir = PathBuf::from(&project_path)
         .join(".bluekit")
         .join("blueprints")
         .join(&blueprint_id);
-    
-    if !global_blueprint_dir.exists() {
+
+    if tokio::fs::metadata(&global_blueprint_dir).await.is_err() {
         return Err(format!("Blueprint not found: {}", blueprint_id));
     }
-    
+
     // Create project blueprint directory
-    fs::create_dir_all(&project_blueprint_dir)
+    tokio::fs::create_dir_all(&project_blueprint_dir)
+        .await
         .map_err(|e| format!("Failed to create blueprint directory: {}", e))?;
-    
+
     // Copy all files from global blueprint to project
-    copy_directory_recursive(&global_blueprint_dir, &project_blueprint_dir)?;
-    
+    copy_directory_recursive(&global_blueprint_dir, &project_blueprint_dir).await?;
+
     Ok(format!("Blueprint {} copied to project", blueprint_id))
 }
 
-/// Helper function to copy directory recursively
-fn copy_directory_recursive(src: &Path, dst: &Path) -> Result<(), String> {
-    if !src.is_dir() {
+/// Copy a directory tree via an explicit queue, since async fns can't recurse without boxing
+async fn copy_directory_recursive(src: &Path, dst: &Path) -> Result<(), String> {
+    if tokio::fs::metadata(src)
+        .await
+        .map(|m| !m.is_dir())
+        .unwrap_or(true)
+    {
         return Err("Source is not a directory".to_string());
     }
-    
-    fs::create_dir_all(dst)
-        .map_err(|e| format!("Failed to create destination directory: {}", e))?;
-    
-    let entries = fs::read_dir(src)
-        .map_err(|e| format!("Failed to read source directory: {}", e))?;
-    
-    for entry in entries {
-        let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
-        let path = entry.path();
-        let file_name = path.file_name().ok_or("Invalid file name")?;
-        let dst_path = dst.join(file_name);
-        
-        if path.is_dir() {
-            copy_directory_recursive(&path, &dst_path)?;
-        } else {
-            fs::copy(&path, &dst_path)
-                .map_err(|e| format!("Failed to copy file {:?}: {}", path, e))?;
+
+    let mut queue: VecDeque<(PathBuf, PathBuf)> = VecDeque::new();
+    queue.push_back((src.to_path_buf(), dst.to_path_buf()));
+
+    while let Some((src_dir, dst_dir)) = queue.pop_front() {
+        tokio::fs::create_dir_all(&dst_dir)
+            .await
+            .map_err(|e| format!("Failed to create destination directory: {}", e))?;
+
+        let mut entries = tokio::fs::read_dir(&src_dir)
+            .await
+            .map_err(|e| format!("Failed to read source directory: {}", e))?;
+
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|e| format!("Failed to read directory entry: {}", e))?
+        {
+            let path = entry.path();
+            let file_name = path.file_name().ok_or("Invalid file name")?;
+            let dst_path = dst_dir.join(file_name);
+
+            if path.is_dir() {
+                queue.push_back((path, dst_path));
+            } else {
+                tokio::fs::copy(&path, &dst_path)
+                    .await
+                    .map_err(|e| format!("Failed to copy file {:?}: {}", path, e))?;
+            }
         }
     }
-    
+
     Ok(())
 }
 
+/// Create a new blueprint under a fresh UUID, seeded with a minimal template
+#[tauri::command]
+pub async fn create_blueprint(project_path: String, name: String) -> Result<String, String> {
+    let id = Uuid::new_v4().to_string();
+    let blueprint_dir = PathBuf::from(&project_path)
+        .join(".bluekit")
+        .join("blueprints")
+        .join(&id);
+
+    tokio::fs::create_dir_all(&blueprint_dir)
+        .await
+        .map_err(|e| format!("Failed to create blueprint directory: {}", e))?;
+
+    let template = format!(
+        "---\nname: {}\ncreated_at: {}\n---\n\n# {}\n",
+        yaml_quote(&name),
+        current_unix_timestamp(),
+        name
+    );
+
+    let index_path = blueprint_dir.join("index.md");
+    write_file(index_path.to_str().unwrap().to_string(), template).await?;
+
+    Ok(id)
+}
+
+/// Create a new scrapbook entry under a fresh UUID, seeded with a minimal template
+#[tauri::command]
+pub async fn create_scrapbook_item(project_path: String, title: String) -> Result<String, String> {
+    let id = Uuid::new_v4().to_string();
+    let scrapbook_dir = PathBuf::from(&project_path).join(".bluekit").join("scrapbook");
+
+    tokio::fs::create_dir_all(&scrapbook_dir)
+        .await
+        .map_err(|e| format!("Failed to create scrapbook directory: {}", e))?;
+
+    let template = format!(
+        "---\ntitle: {}\ncreated_at: {}\n---\n\n{}\n",
+        yaml_quote(&title),
+        current_unix_timestamp(),
+        title
+    );
+
+    let item_path = scrapbook_dir.join(format!("{}.md", id));
+    write_file(item_path.to_str().unwrap().to_string(), template).await?;
+
+    Ok(id)
+}
+
+/// Quote and escape a string as a double-quoted YAML scalar, for safe use in frontmatter
+fn yaml_quote(value: &str) -> String {
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+        .replace('\t', "\\t");
+
+    format!("\"{}\"", escaped)
+}
+
+/// Seconds since the Unix epoch, used as a created-at stamp in templates
+fn current_unix_timestamp() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
 /// Get scrapbook items from project
 #[tauri::command]
-pub fn get_scrapbook_items(project_path: String) -> Result<Vec<String>, String> {
+pub async fn get_scrapbook_items(project_path: String) -> Result<Vec<String>, String> {
     let scrapbook_dir = PathBuf::from(&project_path).join(".bluekit").join("scrapbook");
-    
-    if !scrapbook_dir.exists() {
+
+    if tokio::fs::metadata(&scrapbook_dir).await.is_err() {
         return Ok(Vec::new());
     }
-    
-    let entries = fs::read_dir(&scrapbook_dir)
+
+    let mut entries = tokio::fs::read_dir(&scrapbook_dir)
+        .await
         .map_err(|e| format!("Failed to read scrapbook directory: {}", e))?;
-    
+
     let mut items = Vec::new();
-    for entry in entries {
-        let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
+    while let Some(entry) = entries
+        .next_entry()
+        .await
+        .map_err(|e| format!("Failed to read directory entry: {}", e))?
+    {
         let path = entry.path();
-        
+
         if path.is_file() {
             if let Some(file_name) = path.file_name().and_then(|n| n.to_str()) {
                 items.push(file_name.to_string());
             }
         }
     }
-    
+
     Ok(items)
 }
 
 /// Get all markdown files from a folder
 #[tauri::command]
-pub fn get_folder_markdown_files(folder_path: String) -> Result<Vec<String>, String> {
+pub async fn get_folder_markdown_files(folder_path: String) -> Result<Vec<String>, String> {
     let folder = PathBuf::from(&folder_path);
-    
-    if !folder.exists() {
-        return Err(format!("Folder does not exist: {}", folder_path));
-    }
-    
-    if !folder.is_dir() {
+
+    let metadata = tokio::fs::metadata(&folder)
+        .await
+        .map_err(|_| format!("Folder does not exist: {}", folder_path))?;
+
+    if !metadata.is_dir() {
         return Err(format!("Path is not a directory: {}", folder_path));
     }
-    
-    let entries = fs::read_dir(&folder)
+
+    let mut entries = tokio::fs::read_dir(&folder)
+        .await
         .map_err(|e| format!("Failed to read directory: {}", e))?;
-    
+
     let mut md_files = Vec::new();
-    for entry in entries {
-        let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
+    while let Some(entry) = entries
+        .next_entry()
+        .await
+        .map_err(|e| format!("Failed to read directory entry: {}", e))?
+    {
         let path = entry.path();
-        
+
         if path.is_file() {
             if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
                 if ext == "md" {
@@ -259,42 +425,46 @@ pub fn get_folder_markdown_files(folder_path: String) -> Result<Vec<String>, Str
             }
         }
     }
-    
+
     Ok(md_files)
 }
 
 /// Get all blueprints from project
 #[tauri::command]
-pub fn get_blueprints(project_path: String) -> Result<Vec<String>, String> {
+pub async fn get_blueprints(project_path: String) -> Result<Vec<String>, String> {
     let blueprints_dir = PathBuf::from(&project_path)
         .join(".bluekit")
         .join("blueprints");
-    
-    if !blueprints_dir.exists() {
+
+    if tokio::fs::metadata(&blueprints_dir).await.is_err() {
         return Ok(Vec::new());
     }
-    
-    let entries = fs::read_dir(&blueprints_dir)
+
+    let mut entries = tokio::fs::read_dir(&blueprints_dir)
+        .await
         .map_err(|e| format!("Failed to read blueprints directory: {}", e))?;
-    
+
     let mut blueprints = Vec::new();
-    for entry in entries {
-        let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
+    while let Some(entry) = entries
+        .next_entry()
+        .await
+        .map_err(|e| format!("Failed to read directory entry: {}", e))?
+    {
         let path = entry.path();
-        
+
         if path.is_dir() {
             if let Some(dir_name) = path.file_name().and_then(|n| n.to_str()) {
                 blueprints.push(dir_name.to_string());
             }
         }
     }
-    
+
     Ok(blueprints)
 }
 
 /// Get a specific blueprint task file
 #[tauri::command]
-pub fn get_blueprint_task_file(
+pub async fn get_blueprint_task_file(
     project_path: String,
     blueprint_id: String,
     task_file: String,
@@ -304,40 +474,215 @@ pub fn get_blueprint_task_file(
         .join("blueprints")
         .join(&blueprint_id)
         .join(&task_file);
-    
-    if !task_path.exists() {
+
+    if tokio::fs::metadata(&task_path).await.is_err() {
         return Err(format!("Task file does not exist: {:?}", task_path));
     }
-    
-    read_file(task_path.to_str().unwrap().to_string())
+
+    read_file(task_path.to_str().unwrap().to_string()).await
 }
 
 /// Get all diagram files from project
 #[tauri::command]
-pub fn get_project_diagrams(project_path: String) -> Result<Vec<String>, String> {
+pub async fn get_project_diagrams(project_path: String) -> Result<Vec<String>, String> {
     let diagrams_dir = PathBuf::from(&project_path)
         .join(".bluekit")
         .join("diagrams");
-    
-    if !diagrams_dir.exists() {
+
+    if tokio::fs::metadata(&diagrams_dir).await.is_err() {
         return Ok(Vec::new());
     }
-    
-    let entries = fs::read_dir(&diagrams_dir)
+
+    let mut entries = tokio::fs::read_dir(&diagrams_dir)
+        .await
         .map_err(|e| format!("Failed to read diagrams directory: {}", e))?;
-    
+
     let mut diagrams = Vec::new();
-    for entry in entries {
-        let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
+    while let Some(entry) = entries
+        .next_entry()
+        .await
+        .map_err(|e| format!("Failed to read directory entry: {}", e))?
+    {
         let path = entry.path();
-        
+
         if path.is_file() {
             if let Some(file_name) = path.file_name().and_then(|n| n.to_str()) {
                 diagrams.push(file_name.to_string());
             }
         }
     }
-    
+
     Ok(diagrams)
 }
 
+/// A single file or directory discovered while walking a tree
+#[derive(Debug, Clone, Serialize)]
+pub struct DirEntry {
+    pub relative_path: String,
+    pub is_dir: bool,
+    pub size: u64,
+    pub modified_ms: u64,
+}
+
+/// Distinguishes which step of visiting a subpath failed
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WalkErrorKind {
+    Open,
+    Read,
+}
+
+/// A failure encountered for a single subpath during the walk
+#[derive(Debug, Clone, Serialize)]
+pub struct WalkError {
+    pub path: String,
+    pub kind: WalkErrorKind,
+    pub message: String,
+}
+
+/// Result of a recursive directory walk, possibly partial
+#[derive(Debug, Clone, Serialize)]
+pub struct ReadDirRecursiveResult {
+    pub entries: Vec<DirEntry>,
+    pub errors: Vec<WalkError>,
+    pub timed_out: bool,
+}
+
+/// Recursively enumerate a directory tree as a flat list of entries, breadth-first
+#[tauri::command]
+pub async fn read_dir_recursive(
+    root: String,
+    extensions: Option<Vec<String>>,
+    max_depth: Option<usize>,
+    timeout_ms: Option<u64>,
+) -> Result<ReadDirRecursiveResult, String> {
+    let root_path = PathBuf::from(&root);
+
+    if tokio::fs::metadata(&root_path).await.is_err() {
+        return Err(format!("Path does not exist: {}", root));
+    }
+
+    let deadline = timeout_ms.map(|ms| Instant::now() + Duration::from_millis(ms));
+
+    let mut entries = Vec::new();
+    let mut errors = Vec::new();
+    let mut timed_out = false;
+
+    let mut queue: VecDeque<(PathBuf, usize)> = VecDeque::new();
+    queue.push_back((root_path.clone(), 0));
+
+    // Re-check the deadline every this many entries within a single directory,
+    // not just once per directory, so a directory with a huge number of
+    // entries can't walk straight through a timeout.
+    const DEADLINE_CHECK_INTERVAL: usize = 256;
+
+    'outer: while let Some((dir, depth)) = queue.pop_front() {
+        if let Some(deadline) = deadline {
+            if Instant::now() >= deadline {
+                timed_out = true;
+                break;
+            }
+        }
+
+        let mut read_dir = match tokio::fs::read_dir(&dir).await {
+            Ok(read_dir) => read_dir,
+            Err(e) => {
+                errors.push(WalkError {
+                    path: dir.to_string_lossy().to_string(),
+                    kind: WalkErrorKind::Open,
+                    message: e.to_string(),
+                });
+                continue;
+            }
+        };
+
+        let mut entries_seen = 0usize;
+
+        loop {
+            if let Some(deadline) = deadline {
+                if entries_seen % DEADLINE_CHECK_INTERVAL == 0 && Instant::now() >= deadline {
+                    timed_out = true;
+                    break 'outer;
+                }
+            }
+            entries_seen += 1;
+
+            let entry = match read_dir.next_entry().await {
+                Ok(Some(entry)) => entry,
+                Ok(None) => break,
+                Err(e) => {
+                    errors.push(WalkError {
+                        path: dir.to_string_lossy().to_string(),
+                        kind: WalkErrorKind::Read,
+                        message: e.to_string(),
+                    });
+                    break;
+                }
+            };
+
+            let path = entry.path();
+            let relative_path = path
+                .strip_prefix(&root_path)
+                .unwrap_or(&path)
+                .to_string_lossy()
+                .to_string();
+
+            let metadata = match entry.metadata().await {
+                Ok(metadata) => metadata,
+                Err(e) => {
+                    errors.push(WalkError {
+                        path: path.to_string_lossy().to_string(),
+                        kind: WalkErrorKind::Read,
+                        message: e.to_string(),
+                    });
+                    continue;
+                }
+            };
+
+            let modified_ms = metadata
+                .modified()
+                .ok()
+                .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or(0);
+
+            if metadata.is_dir() {
+                entries.push(DirEntry {
+                    relative_path,
+                    is_dir: true,
+                    size: 0,
+                    modified_ms,
+                });
+
+                let within_depth = max_depth.map(|max| depth < max).unwrap_or(true);
+                if within_depth {
+                    queue.push_back((path, depth + 1));
+                }
+            } else {
+                let matches_extension = match &extensions {
+                    None => true,
+                    Some(exts) => path
+                        .extension()
+                        .and_then(|e| e.to_str())
+                        .map(|ext| exts.iter().any(|wanted| wanted == ext))
+                        .unwrap_or(false),
+                };
+
+                if matches_extension {
+                    entries.push(DirEntry {
+                        relative_path,
+                        is_dir: false,
+                        size: metadata.len(),
+                        modified_ms,
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(ReadDirRecursiveResult {
+        entries,
+        errors,
+        timed_out,
+    })
+}
@@ -5,6 +5,7 @@ mod watcher;
 
 pub fn run() {
     tauri::Builder::default()
+        .manage(state::NotificationSettings::default())
         .invoke_handler(tauri::generate_handler![
             commands::ping,
             commands::get_app_info,
@@ -13,13 +14,18 @@ pub fn run() {
             commands::get_project_registry,
             commands::watch_project_kits,
             commands::read_file,
+            commands::write_file,
             commands::copy_kit_to_project,
             commands::copy_blueprint_to_project,
+            commands::create_blueprint,
+            commands::create_scrapbook_item,
             commands::get_scrapbook_items,
             commands::get_folder_markdown_files,
             commands::get_blueprints,
             commands::get_blueprint_task_file,
             commands::get_project_diagrams,
+            commands::read_dir_recursive,
+            commands::set_notifications_enabled,
         ])
         .setup(|app| {
             let app_handle = app.handle();
@@ -28,6 +34,8 @@ pub fn run() {
                     app_handle.clone(),
                     registry_path,
                     "project-registry-changed".to_string(),
+                    watcher::DEFAULT_DEBOUNCE,
+                    true,
                 ) {
                     eprintln!("Failed to start file watcher: {}", e);
                 }
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 /// Application state structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,3 +17,35 @@ impl Default for AppState {
     }
 }
 
+/// Runtime preference for whether watchers may raise native notifications
+///
+/// Managed as Tauri state so `set_notifications_enabled` can flip it at
+/// runtime without tearing down and re-registering the file watchers.
+pub struct NotificationSettings {
+    enabled: AtomicBool,
+}
+
+impl NotificationSettings {
+    pub fn new(enabled: bool) -> Self {
+        NotificationSettings {
+            enabled: AtomicBool::new(enabled),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+    }
+}
+
+impl Default for NotificationSettings {
+    fn default() -> Self {
+        // Opt-in: notifications stay off until something explicitly calls
+        // `set_notifications_enabled(true)`.
+        NotificationSettings::new(false)
+    }
+}
+
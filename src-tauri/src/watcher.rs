@@ -1,19 +1,156 @@
+use crate::state::NotificationSettings;
+use notify::event::{ModifyKind, RenameMode};
 use notify::{Config, Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
-use std::path::PathBuf;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::mpsc;
+use std::time::Duration;
+use tauri::api::notification::Notification;
 use tauri::{AppHandle, Manager};
 
-/// Watch a single file for changes and emit Tauri events
+/// Default quiet period used when a caller doesn't need a tighter one
+pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// What kind of change happened to a watched path
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Created,
+    Modified,
+    Removed,
+}
+
+/// A single watched-path change, ready to patch a frontend's view incrementally
+#[derive(Debug, Clone, Serialize)]
+pub struct WatchChange {
+    pub path: String,
+    pub kind: ChangeKind,
+}
+
+/// Classify a raw `notify` event into per-path change kinds
+///
+/// Most event kinds map straightforwardly (`Create` -> `Created`, etc.), but
+/// renames need special handling: `notify` can surface a rename either as a
+/// single `Modify(Name(Both))` event carrying `[from, to]`, or as a split
+/// `Modify(Name(From))` / `Modify(Name(To))` pair. Both shapes are mapped to
+/// explicit `Removed`/`Created` entries so a move within the watched tree
+/// looks the same to callers as a delete followed by a create.
+fn classify_event(event: &Event) -> Vec<(PathBuf, ChangeKind)> {
+    match &event.kind {
+        EventKind::Create(_) => event
+            .paths
+            .iter()
+            .map(|p| (p.clone(), ChangeKind::Created))
+            .collect(),
+        EventKind::Remove(_) => event
+            .paths
+            .iter()
+            .map(|p| (p.clone(), ChangeKind::Removed))
+            .collect(),
+        EventKind::Modify(ModifyKind::Name(RenameMode::From)) => event
+            .paths
+            .iter()
+            .map(|p| (p.clone(), ChangeKind::Removed))
+            .collect(),
+        EventKind::Modify(ModifyKind::Name(RenameMode::To)) => event
+            .paths
+            .iter()
+            .map(|p| (p.clone(), ChangeKind::Created))
+            .collect(),
+        EventKind::Modify(ModifyKind::Name(RenameMode::Both)) => {
+            let mut changes = Vec::new();
+            if let Some(from) = event.paths.first() {
+                changes.push((from.clone(), ChangeKind::Removed));
+            }
+            if let Some(to) = event.paths.get(1) {
+                changes.push((to.clone(), ChangeKind::Created));
+            }
+            changes
+        }
+        EventKind::Modify(_) => event
+            .paths
+            .iter()
+            .map(|p| (p.clone(), ChangeKind::Modified))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn is_markdown(path: &PathBuf) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext == "md")
+        .unwrap_or(false)
+}
+
+/// Resolve the project name for a watched path via its `.bluekit` ancestor
+///
+/// A watched path always lives somewhere under `<project>/.bluekit/...`, so
+/// the project name is the file name of the parent of the nearest ancestor
+/// directory named `.bluekit`.
+fn project_name_from_watched_path(path: &Path) -> Option<String> {
+    for ancestor in path.ancestors() {
+        if ancestor.file_name().and_then(|n| n.to_str()) == Some(".bluekit") {
+            return ancestor
+                .parent()
+                .and_then(|p| p.file_name())
+                .and_then(|n| n.to_str())
+                .map(|s| s.to_string());
+        }
+    }
+    None
+}
+
+/// Raise a native OS notification if notifications are enabled and the app
+/// window is currently out of focus
+///
+/// Called once per debounced batch so a single save produces at most one
+/// notification rather than a storm.
+fn notify_if_unfocused(app_handle: &AppHandle, notifications: &NotificationSettings, body: String) {
+    if !notifications.is_enabled() {
+        return;
+    }
+
+    let is_focused = app_handle
+        .get_window("main")
+        .and_then(|window| window.is_focused().ok())
+        .unwrap_or(false);
+
+    if is_focused {
+        return;
+    }
+
+    let identifier = app_handle.config().tauri.bundle.identifier.clone();
+    if let Err(e) = Notification::new(identifier)
+        .title("BlueKit")
+        .body(body)
+        .show()
+    {
+        eprintln!("Failed to show notification: {}", e);
+    }
+}
+
+/// Watch a single file for changes and emit a single debounced Tauri event
+///
+/// Raw `notify` events are coalesced: after each event the handler waits up
+/// to `debounce` for another one to arrive before emitting, so a single
+/// editor save (which often fires several raw events) produces one
+/// `event_name` emission instead of a storm. When `notify_on_change` is set,
+/// the same debounced batch also raises a native OS notification if the app
+/// window is unfocused and notifications are enabled at runtime.
 pub fn watch_file(
     app_handle: AppHandle,
     file_path: PathBuf,
     event_name: String,
+    debounce: Duration,
+    notify_on_change: bool,
 ) -> Result<(), String> {
     let file_path_clone = file_path.clone();
-    
+
     // Create a channel to receive file events
     let (tx, rx) = mpsc::channel();
-    
+
     // Create a watcher
     let mut watcher = RecommendedWatcher::new(
         move |result: Result<Event, notify::Error>| {
@@ -34,16 +171,44 @@ pub fn watch_file(
         .watch(&file_path, RecursiveMode::NonRecursive)
         .map_err(|e| format!("Failed to watch file: {:?}: {}", file_path, e))?;
 
-    // Spawn a task to handle events
+    // Run the debounce loop on a blocking-pool thread: `recv_timeout` blocks
+    // for up to `debounce` per iteration, and moving `watcher` in here (rather
+    // than leaving it to drop when this function returns) keeps the
+    // recommended backend's OS-level watch alive for as long as we're
+    // listening for events.
     let app_handle_clone = app_handle.clone();
-    tokio::spawn(async move {
-        while let Ok(event) = rx.recv() {
-            if let Some(path) = event.paths.first() {
-                if path == &file_path_clone {
-                    if let Err(e) = app_handle_clone.emit_all(&event_name, ()) {
-                        eprintln!("Failed to emit event {}: {}", event_name, e);
+    tokio::task::spawn_blocking(move || {
+        let _watcher = watcher;
+        let mut pending = false;
+
+        loop {
+            match rx.recv_timeout(debounce) {
+                Ok(event) => {
+                    if let Some(path) = event.paths.first() {
+                        if path == &file_path_clone {
+                            pending = true;
+                        }
                     }
                 }
+                Err(mpsc::RecvTimeoutError::Timeout) => {
+                    if pending {
+                        if let Err(e) = app_handle_clone.emit_all(&event_name, ()) {
+                            eprintln!("Failed to emit event {}: {}", event_name, e);
+                        }
+
+                        if notify_on_change {
+                            let notifications = app_handle_clone.state::<NotificationSettings>();
+                            notify_if_unfocused(
+                                &app_handle_clone,
+                                &notifications,
+                                "Project registry changed".to_string(),
+                            );
+                        }
+
+                        pending = false;
+                    }
+                }
+                Err(mpsc::RecvTimeoutError::Disconnected) => break,
             }
         }
     });
@@ -51,29 +216,37 @@ pub fn watch_file(
     Ok(())
 }
 
-/// Watch a directory recursively for .md file changes
+/// Watch a directory recursively for .md file changes, emitting debounced batches
+///
+/// Raw `notify` events are coalesced: affected paths accumulate into a
+/// deduplicated set, and the set is only emitted once no new event has
+/// arrived for `debounce`, so one save doesn't flood the frontend with one
+/// emission per raw filesystem event. When `notify_on_change` is set, the
+/// same debounced batch also raises a native OS notification if the app
+/// window is unfocused and notifications are enabled at runtime.
 pub fn watch_directory(
     app_handle: AppHandle,
     dir_path: PathBuf,
     event_name: String,
+    debounce: Duration,
+    notify_on_change: bool,
 ) -> Result<(), String> {
-    
+    let dir_path_clone = dir_path.clone();
+
     // Create a channel to receive file events
     let (tx, rx) = mpsc::channel();
-    
+
     // Create a watcher
     let mut watcher = RecommendedWatcher::new(
         move |result: Result<Event, notify::Error>| {
             if let Ok(event) = result {
-                if matches!(event.kind, EventKind::Modify(_) | EventKind::Create(_) | EventKind::Remove(_)) {
+                if matches!(
+                    event.kind,
+                    EventKind::Modify(_) | EventKind::Create(_) | EventKind::Remove(_)
+                ) {
                     // Filter for .md files only
-                    let has_md_file = event.paths.iter().any(|p| {
-                        p.extension()
-                            .and_then(|ext| ext.to_str())
-                            .map(|ext| ext == "md")
-                            .unwrap_or(false)
-                    });
-                    
+                    let has_md_file = event.paths.iter().any(is_markdown);
+
                     if has_md_file {
                         if let Err(e) = tx.send(event) {
                             eprintln!("Error sending directory event: {}", e);
@@ -91,30 +264,49 @@ pub fn watch_directory(
         .watch(&dir_path, RecursiveMode::Recursive)
         .map_err(|e| format!("Failed to watch directory: {:?}: {}", dir_path, e))?;
 
-    // Spawn a task to handle events
+    // Run the debounce loop on a blocking-pool thread: `recv_timeout` blocks
+    // for up to `debounce` per iteration, and moving `watcher` in here (rather
+    // than leaving it to drop when this function returns) keeps the
+    // recommended backend's OS-level watch alive for as long as we're
+    // listening for events.
     let app_handle_clone = app_handle.clone();
-    tokio::spawn(async move {
-        while let Ok(event) = rx.recv() {
-            let md_files: Vec<String> = event
-                .paths
-                .iter()
-                .filter_map(|p| {
-                    if p.extension()
-                        .and_then(|ext| ext.to_str())
-                        .map(|ext| ext == "md")
-                        .unwrap_or(false)
-                    {
-                        p.to_str().map(|s| s.to_string())
-                    } else {
-                        None
+    tokio::task::spawn_blocking(move || {
+        let _watcher = watcher;
+        let mut pending_changes: HashMap<String, ChangeKind> = HashMap::new();
+
+        loop {
+            match rx.recv_timeout(debounce) {
+                Ok(event) => {
+                    for (path, kind) in classify_event(&event) {
+                        if is_markdown(&path) {
+                            if let Some(s) = path.to_str() {
+                                pending_changes.insert(s.to_string(), kind);
+                            }
+                        }
+                    }
+                }
+                Err(mpsc::RecvTimeoutError::Timeout) => {
+                    if !pending_changes.is_empty() {
+                        let changes: Vec<WatchChange> = pending_changes
+                            .drain()
+                            .map(|(path, kind)| WatchChange { path, kind })
+                            .collect();
+                        let change_count = changes.len();
+
+                        if let Err(e) = app_handle_clone.emit_all(&event_name, changes) {
+                            eprintln!("Failed to emit event {}: {}", event_name, e);
+                        }
+
+                        if notify_on_change {
+                            let notifications = app_handle_clone.state::<NotificationSettings>();
+                            let project = project_name_from_watched_path(&dir_path_clone)
+                                .unwrap_or_else(|| "project".to_string());
+                            let body = format!("{} kits updated in {}", change_count, project);
+                            notify_if_unfocused(&app_handle_clone, &notifications, body);
+                        }
                     }
-                })
-                .collect();
-            
-            if !md_files.is_empty() {
-                if let Err(e) = app_handle_clone.emit_all(&event_name, md_files) {
-                    eprintln!("Failed to emit event {}: {}", event_name, e);
                 }
+                Err(mpsc::RecvTimeoutError::Disconnected) => break,
             }
         }
     });